@@ -1,8 +1,11 @@
 mod solana_address_lookup_table_instruction;
 pub use solana_address_lookup_table_instruction::*;
+use anchor_lang::prelude::*;
 use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
 use std::str::FromStr;
 
+use crate::error::*;
+
 pub fn id() -> Pubkey {
     Pubkey::from_str(&"AddressLookupTab1e1111111111111111111111111").unwrap()
 }
@@ -25,4 +28,19 @@ pub fn contains(table: &[u8], pubkey: &Pubkey) -> bool {
         .iter()
         .find(|&addr| addr == pubkey)
         .is_some()
+}
+
+/// Checks whether `pubkey` still needs to be added to `table`.
+///
+/// No-ops (returns false) when the address is already present, and errors
+/// when the table has no room left for another address.
+pub fn register_address(table: &[u8], pubkey: &Pubkey) -> Result<bool> {
+    if contains(table, pubkey) {
+        return Ok(false);
+    }
+    require!(
+        addresses(table).len() < LOOKUP_TABLE_MAX_ADDRESSES,
+        MangoError::SomeError
+    );
+    Ok(true)
 }
\ No newline at end of file
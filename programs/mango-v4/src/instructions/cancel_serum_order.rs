@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::dex;
+use arrayref::array_refs;
+use borsh::{BorshDeserialize, BorshSerialize};
+use dex::serum_dex;
+use num_enum::TryFromPrimitive;
+use solana_program::program::invoke_signed;
+use std::io::Write;
+
+use crate::error::*;
+use crate::state::*;
+
+/// Unfortunately CancelOrderInstructionV2 isn't borsh serializable either.
+///
+/// Make a newtype and implement the traits for it, same as for NewOrderInstructionData.
+pub struct CancelOrderInstructionData(pub serum_dex::instruction::CancelOrderInstructionV2);
+
+fn unpack_dex_cancel_order_v2(
+    data: &[u8; 20],
+) -> Option<serum_dex::instruction::CancelOrderInstructionV2> {
+    let (&side_arr, &order_id_bytes) = array_refs![data, 4, 16];
+
+    let side = serum_dex::matching::Side::try_from_primitive(
+        u32::from_le_bytes(side_arr).try_into().ok()?,
+    )
+    .ok()?;
+    let order_id = u128::from_le_bytes(order_id_bytes);
+
+    Some(serum_dex::instruction::CancelOrderInstructionV2 { side, order_id })
+}
+
+impl BorshDeserialize for CancelOrderInstructionData {
+    fn deserialize(buf: &mut &[u8]) -> std::result::Result<Self, std::io::Error> {
+        let data: &[u8; 20] = buf[0..20]
+            .try_into()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::UnexpectedEof, e))?;
+        *buf = &buf[20..];
+        Ok(Self(unpack_dex_cancel_order_v2(data).ok_or(
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                error!(MangoError::SomeError),
+            ),
+        )?))
+    }
+}
+
+impl BorshSerialize for CancelOrderInstructionData {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::result::Result<(), std::io::Error> {
+        let d = &self.0;
+        let side: u8 = d.side.into();
+        writer.write(&(side as u32).to_le_bytes())?;
+        writer.write(&d.order_id.to_le_bytes())?;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CancelSerumOrder<'info> {
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        mut,
+        has_one = group,
+        has_one = owner,
+    )]
+    pub account: AccountLoader<'info, MangoAccount>,
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = account.load()?.spot_open_orders_map.is_registered(serum_market.key(), open_orders.key()) @ MangoError::SomeError,
+    )]
+    pub open_orders: UncheckedAccount<'info>,
+
+    #[account(
+        has_one = group,
+        has_one = serum_program,
+        has_one = serum_market_external,
+    )]
+    pub serum_market: AccountLoader<'info, SerumMarket>,
+
+    pub serum_program: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub serum_market_external: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub market_bids: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub market_asks: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub market_event_queue: UncheckedAccount<'info>,
+}
+
+pub fn cancel_serum_order(
+    ctx: Context<CancelSerumOrder>,
+    order: CancelOrderInstructionData,
+) -> Result<()> {
+    // unwrap our newtype
+    let order = order.0;
+
+    // anchor_spl::dex doesn't wrap cancel_order_v2, so the instruction is built by hand,
+    // the same way mango-v3's liquidation code does it.
+    let instruction = serum_dex::instruction::cancel_order_v2(
+        &dex::ID,
+        &ctx.accounts.serum_market_external.key(),
+        &ctx.accounts.market_bids.key(),
+        &ctx.accounts.market_asks.key(),
+        &ctx.accounts.open_orders.key(),
+        &ctx.accounts.group.key(),
+        &ctx.accounts.market_event_queue.key(),
+        order.side,
+        order.order_id,
+    )
+    .map_err(|_| error!(MangoError::SomeError))?;
+
+    let account_infos = [
+        ctx.accounts.serum_market_external.to_account_info(),
+        ctx.accounts.market_bids.to_account_info(),
+        ctx.accounts.market_asks.to_account_info(),
+        ctx.accounts.open_orders.to_account_info(),
+        ctx.accounts.group.to_account_info(),
+        ctx.accounts.market_event_queue.to_account_info(),
+        ctx.accounts.serum_program.to_account_info(),
+    ];
+
+    let group = ctx.accounts.group.load()?;
+    let seeds = group_seeds!(group);
+    invoke_signed(&instruction, &account_infos, &[seeds])?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use solana_program::program::invoke_signed;
+
+use crate::address_lookup_table;
+use crate::error::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CreateAddressLookupTable<'info> {
+    #[account(has_one = admin)]
+    pub group: AccountLoader<'info, Group>,
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: address is derived from (group, recent_slot) by the ALT program itself
+    #[account(mut)]
+    pub address_lookup_table: UncheckedAccount<'info>,
+
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_address_lookup_table(
+    ctx: Context<CreateAddressLookupTable>,
+    recent_slot: u64,
+) -> Result<()> {
+    require!(
+        *ctx.accounts.address_lookup_table_program.key == address_lookup_table::id(),
+        MangoError::SomeError
+    );
+
+    let group = ctx.accounts.group.load()?;
+    let seeds = group_seeds!(group);
+
+    let (instruction, table_address) = address_lookup_table::create_lookup_table(
+        ctx.accounts.group.key(),
+        ctx.accounts.payer.key(),
+        recent_slot,
+    );
+    require_keys_eq!(table_address, ctx.accounts.address_lookup_table.key());
+
+    invoke_signed(
+        &instruction,
+        &[
+            ctx.accounts.address_lookup_table.to_account_info(),
+            ctx.accounts.group.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::dex;
+use std::mem::size_of;
+
+use crate::error::*;
+use crate::state::*;
+
+/// The size of a serum dex OpenOrders account, including the 12 bytes of
+/// padding dex::init_open_orders expects on either side of its own data.
+const OPEN_ORDERS_SIZE: usize = size_of::<dex::serum_dex::state::OpenOrders>() + 24;
+
+#[derive(Accounts)]
+pub struct CreateSerumOpenOrders<'info> {
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        mut,
+        has_one = group,
+        has_one = owner,
+    )]
+    pub account: AccountLoader<'info, MangoAccount>,
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = group,
+        has_one = serum_program,
+        has_one = serum_market_external,
+    )]
+    pub serum_market: AccountLoader<'info, SerumMarket>,
+    pub serum_program: UncheckedAccount<'info>,
+    pub serum_market_external: UncheckedAccount<'info>,
+
+    // A freshly generated keypair, same as mango-v3's raw-keypair OpenOrders convention:
+    // it signs its own account creation below, then is handed its authority by init_open_orders.
+    #[account(mut)]
+    pub open_orders: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn create_serum_open_orders(ctx: Context<CreateSerumOpenOrders>) -> Result<()> {
+    let group = ctx.accounts.group.load()?;
+    let seeds = group_seeds!(group);
+
+    // Allocate the raw account, owned by the serum dex program, same as mango-v3 did.
+    // `open_orders` is the one signing for its own creation here; `group` isn't an
+    // account in this CPI at all, so there's no seeds to sign with.
+    let rent = &ctx.accounts.rent;
+    anchor_lang::system_program::create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::CreateAccount {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.open_orders.to_account_info(),
+            },
+        ),
+        rent.minimum_balance(OPEN_ORDERS_SIZE),
+        OPEN_ORDERS_SIZE as u64,
+        &ctx.accounts.serum_program.key(),
+    )?;
+
+    dex::init_open_orders(CpiContext::new(
+        ctx.accounts.serum_program.to_account_info(),
+        dex::InitOpenOrders {
+            open_orders: ctx.accounts.open_orders.to_account_info(),
+            authority: ctx.accounts.group.to_account_info(),
+            market: ctx.accounts.serum_market_external.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        },
+    )
+    .with_signer(&[seeds]))?;
+
+    drop(group);
+    let mut account = ctx.accounts.account.load_mut()?;
+    account.spot_open_orders_map.register(
+        ctx.accounts.serum_market.key(),
+        ctx.accounts.open_orders.key(),
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseSerumOpenOrders<'info> {
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        mut,
+        has_one = group,
+        has_one = owner,
+    )]
+    pub account: AccountLoader<'info, MangoAccount>,
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = group,
+        has_one = serum_program,
+        has_one = serum_market_external,
+    )]
+    pub serum_market: AccountLoader<'info, SerumMarket>,
+    pub serum_program: UncheckedAccount<'info>,
+    pub serum_market_external: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = account.load()?.spot_open_orders_map.is_registered(serum_market.key(), open_orders.key()) @ MangoError::SomeError,
+    )]
+    pub open_orders: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub sol_destination: UncheckedAccount<'info>,
+}
+
+pub fn close_serum_open_orders(ctx: Context<CloseSerumOpenOrders>) -> Result<()> {
+    let group = ctx.accounts.group.load()?;
+    let seeds = group_seeds!(group);
+
+    // dex::close_open_orders requires the OpenOrders account to be empty
+    // (no free/locked balances, no orders resting on the book).
+    dex::close_open_orders(
+        CpiContext::new(
+            ctx.accounts.serum_program.to_account_info(),
+            dex::CloseOpenOrders {
+                open_orders: ctx.accounts.open_orders.to_account_info(),
+                authority: ctx.accounts.group.to_account_info(),
+                destination: ctx.accounts.sol_destination.to_account_info(),
+                market: ctx.accounts.serum_market_external.to_account_info(),
+            },
+        )
+        .with_signer(&[seeds]),
+    )?;
+
+    drop(group);
+    let mut account = ctx.accounts.account.load_mut()?;
+    account
+        .spot_open_orders_map
+        .deregister(ctx.accounts.serum_market.key())?;
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use solana_program::program::invoke_signed;
+use std::collections::HashSet;
+
+use crate::address_lookup_table;
+use crate::error::*;
+use crate::state::*;
+
+/// Adds the group's banks/vaults/oracles/serum markets to its address lookup
+/// table so clients can compress remaining_accounts into a versioned
+/// transaction instead of hitting the legacy 35-account limit.
+#[derive(Accounts)]
+pub struct ExtendAddressLookupTable<'info> {
+    #[account(has_one = admin)]
+    pub group: AccountLoader<'info, Group>,
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: ownership/length checked in the handler below; the table's stored
+    /// authority is only implicitly enforced by the ALT program CPI failing if
+    /// `group` isn't the real authority.
+    #[account(mut)]
+    pub address_lookup_table: UncheckedAccount<'info>,
+
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn extend_address_lookup_table(
+    ctx: Context<ExtendAddressLookupTable>,
+    new_addresses: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        *ctx.accounts.address_lookup_table_program.key == address_lookup_table::id(),
+        MangoError::SomeError
+    );
+    require!(
+        ctx.accounts.address_lookup_table.owner == &address_lookup_table::id()
+            && ctx.accounts.address_lookup_table.data_len()
+                >= address_lookup_table::LOOKUP_TABLE_META_SIZE,
+        MangoError::SomeError
+    );
+
+    let to_add = {
+        let table_data = ctx.accounts.address_lookup_table.try_borrow_data()?;
+        let mut already_present = address_lookup_table::addresses(&table_data).len();
+        let mut seen = HashSet::new();
+        let mut to_add = Vec::with_capacity(new_addresses.len());
+        for pubkey in new_addresses {
+            if !seen.insert(pubkey) {
+                continue;
+            }
+            if !address_lookup_table::register_address(&table_data, &pubkey)? {
+                continue;
+            }
+            require!(
+                already_present < address_lookup_table::LOOKUP_TABLE_MAX_ADDRESSES,
+                MangoError::SomeError
+            );
+            already_present += 1;
+            to_add.push(pubkey);
+        }
+        to_add
+    };
+
+    if to_add.is_empty() {
+        return Ok(());
+    }
+
+    let group = ctx.accounts.group.load()?;
+    let seeds = group_seeds!(group);
+
+    let instruction = address_lookup_table::extend_lookup_table(
+        ctx.accounts.address_lookup_table.key(),
+        ctx.accounts.group.key(),
+        Some(ctx.accounts.payer.key()),
+        to_add,
+    );
+
+    invoke_signed(
+        &instruction,
+        &[
+            ctx.accounts.address_lookup_table.to_account_info(),
+            ctx.accounts.group.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+
+    Ok(())
+}
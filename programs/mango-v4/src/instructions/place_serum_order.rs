@@ -113,7 +113,7 @@ pub struct PlaceSerumOrder<'info> {
 
     #[account(
         mut,
-        //constraint = open_orders in account.spot_open_orders_map
+        constraint = account.load()?.spot_open_orders_map.is_registered(serum_market.key(), open_orders.key()) @ MangoError::SomeError,
     )]
     pub open_orders: UncheckedAccount<'info>,
 
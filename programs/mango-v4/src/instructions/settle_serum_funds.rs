@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::dex;
+use anchor_spl::token::{Token, TokenAccount};
+use fixed::types::I80F48;
+
+use crate::error::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SettleSerumFunds<'info> {
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        mut,
+        has_one = group,
+        has_one = owner,
+    )]
+    pub account: AccountLoader<'info, MangoAccount>,
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = account.load()?.spot_open_orders_map.is_registered(serum_market.key(), open_orders.key()) @ MangoError::SomeError,
+    )]
+    pub open_orders: UncheckedAccount<'info>,
+
+    #[account(
+        has_one = group,
+        has_one = serum_program,
+        has_one = serum_market_external,
+    )]
+    pub serum_market: AccountLoader<'info, SerumMarket>,
+
+    pub serum_program: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub serum_market_external: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub market_base_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub market_quote_vault: UncheckedAccount<'info>,
+    pub market_vault_signer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = group,
+        constraint = quote_bank.load()?.vault == quote_vault.key() @ MangoError::SomeError,
+    )]
+    pub quote_bank: AccountLoader<'info, Bank>,
+    #[account(mut)]
+    pub quote_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        has_one = group,
+        constraint = base_bank.load()?.vault == base_vault.key() @ MangoError::SomeError,
+    )]
+    pub base_bank: AccountLoader<'info, Bank>,
+    #[account(mut)]
+    pub base_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn settle_serum_funds(ctx: Context<SettleSerumFunds>) -> Result<()> {
+    let before_base_vault = ctx.accounts.base_vault.amount;
+    let before_quote_vault = ctx.accounts.quote_vault.amount;
+
+    let context = CpiContext::new(
+        ctx.accounts.serum_program.to_account_info(),
+        dex::SettleFunds {
+            market: ctx.accounts.serum_market_external.to_account_info(),
+            open_orders: ctx.accounts.open_orders.to_account_info(),
+            open_orders_authority: ctx.accounts.group.to_account_info(),
+            coin_vault: ctx.accounts.market_base_vault.to_account_info(),
+            pc_vault: ctx.accounts.market_quote_vault.to_account_info(),
+            coin_wallet: ctx.accounts.base_vault.to_account_info(),
+            pc_wallet: ctx.accounts.quote_vault.to_account_info(),
+            vault_signer: ctx.accounts.market_vault_signer.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    );
+
+    let group = ctx.accounts.group.load()?;
+    let seeds = group_seeds!(group);
+    dex::settle_funds(context.with_signer(&[seeds]))?;
+
+    ctx.accounts.base_vault.reload()?;
+    ctx.accounts.quote_vault.reload()?;
+    let base_settled = I80F48::from(ctx.accounts.base_vault.amount - before_base_vault);
+    let quote_settled = I80F48::from(ctx.accounts.quote_vault.amount - before_quote_vault);
+
+    let mut account = ctx.accounts.account.load_mut()?;
+    require!(account.is_bankrupt == 0, MangoError::IsBankrupt);
+
+    if base_settled > 0 {
+        let mut base_bank = ctx.accounts.base_bank.load_mut()?;
+        let token_index = base_bank.token_index;
+        let (position, _) = account.tokens.get_mut_or_create(token_index)?;
+        base_bank.deposit(position, base_settled)?;
+    }
+    if quote_settled > 0 {
+        let mut quote_bank = ctx.accounts.quote_bank.load_mut()?;
+        let token_index = quote_bank.token_index;
+        let (position, _) = account.tokens.get_mut_or_create(token_index)?;
+        quote_bank.deposit(position, quote_settled)?;
+    }
+
+    // Health check
+    let health = compute_health(&account, ctx.remaining_accounts)?;
+    msg!("health: {}", health);
+    require!(health >= 0, MangoError::SomeError);
+
+    Ok(())
+}
@@ -48,13 +48,25 @@ impl<'info> TokenDeposit<'info> {
     }
 }
 
-// TODO: It may make sense to have the token_index passed in from the outside.
-//       That would save a lot of computation that needs to go into finding the
-//       right index for the mint.
-pub fn token_deposit(ctx: Context<TokenDeposit>, amount: u64) -> Result<()> {
+// token_index is optional: when the caller already knows it (e.g. it cached
+// it from the bank account it's passing in), passing it here lets us assert
+// it directly against the loaded bank rather than trusting the caller blindly.
+// The bank still has to be loaded either way; the real compute savings are in
+// the token_deposit_into_existing fast path below, which looks up the existing
+// position with tokens.get_mut and skips get_mut_or_create.
+pub fn token_deposit(
+    ctx: Context<TokenDeposit>,
+    amount: u64,
+    token_index: Option<u16>,
+) -> Result<()> {
     require!(amount > 0, MangoError::SomeError);
 
-    let token_index = ctx.accounts.bank.load()?.token_index;
+    let bank = ctx.accounts.bank.load()?;
+    if let Some(token_index) = token_index {
+        require_eq!(token_index, bank.token_index, MangoError::SomeError);
+    }
+    let token_index = bank.token_index;
+    drop(bank);
 
     // Get the account's position for that token index
     let mut account = ctx.accounts.account.load_mut()?;
@@ -90,5 +102,42 @@ pub fn token_deposit(ctx: Context<TokenDeposit>, amount: u64) -> Result<()> {
         account.tokens.deactivate(position_index);
     }
 
+    Ok(())
+}
+
+/// Batched top-up variant of [token_deposit] for the common case where the
+/// account already holds an active position for this token: it skips the
+/// get_mut_or_create scan through the token positions and errors instead of
+/// silently activating a new slot.
+pub fn token_deposit_into_existing(
+    ctx: Context<TokenDeposit>,
+    amount: u64,
+    token_index: u16,
+) -> Result<()> {
+    require!(amount > 0, MangoError::SomeError);
+
+    let bank = ctx.accounts.bank.load()?;
+    require_eq!(token_index, bank.token_index, MangoError::SomeError);
+    drop(bank);
+
+    let mut account = ctx.accounts.account.load_mut()?;
+    require!(account.is_bankrupt == 0, MangoError::IsBankrupt);
+
+    let position = account
+        .tokens
+        .get_mut(token_index)
+        .ok_or_else(|| error!(MangoError::SomeError))?;
+
+    {
+        let mut bank = ctx.accounts.bank.load_mut()?;
+        bank.deposit(position, I80F48::from(amount))?;
+    }
+
+    token::transfer(ctx.accounts.transfer_ctx(), amount)?;
+
+    let health =
+        compute_health_from_fixed_accounts(&account, HealthType::Init, ctx.remaining_accounts)?;
+    msg!("health: {}", health);
+
     Ok(())
 }
\ No newline at end of file
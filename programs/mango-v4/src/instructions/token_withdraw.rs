@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use fixed::types::I80F48;
+
+use crate::error::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct TokenWithdraw<'info> {
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        mut,
+        has_one = group,
+        has_one = owner,
+    )]
+    pub account: AccountLoader<'info, MangoAccount>,
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = group,
+        has_one = vault,
+        // the mints of bank/vault/token_account are implicitly the same because
+        // spl::token::transfer succeeds between token_account and vault
+    )]
+    pub bank: AccountLoader<'info, Bank>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> TokenWithdraw<'info> {
+    pub fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        let program = self.token_program.to_account_info();
+        let accounts = token::Transfer {
+            from: self.vault.to_account_info(),
+            to: self.token_account.to_account_info(),
+            authority: self.group.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+pub fn token_withdraw(ctx: Context<TokenWithdraw>, amount: u64, allow_borrow: bool) -> Result<()> {
+    require!(amount > 0, MangoError::SomeError);
+
+    let token_index = ctx.accounts.bank.load()?.token_index;
+
+    // Get the account's position for that token index
+    let mut account = ctx.accounts.account.load_mut()?;
+    require!(account.is_bankrupt == 0, MangoError::IsBankrupt);
+
+    let (position, position_index) = account.tokens.get_mut_or_create(token_index)?;
+
+    // Update the bank and position
+    let position_is_active = {
+        let mut bank = ctx.accounts.bank.load_mut()?;
+        bank.withdraw(position, I80F48::from(amount), allow_borrow)?
+    };
+
+    // Transfer the actual tokens
+    let group = ctx.accounts.group.load()?;
+    let seeds = group_seeds!(group);
+    token::transfer(ctx.accounts.transfer_ctx().with_signer(&[seeds]), amount)?;
+
+    //
+    // Health check
+    //
+    let health =
+        compute_health_from_fixed_accounts(&account, HealthType::Init, ctx.remaining_accounts)?;
+    msg!("health: {}", health);
+    require!(health >= 0, MangoError::SomeError);
+
+    //
+    // Deactivate the position only after the health check because the user passed in
+    // remaining_accounts for all banks/oracles, including the account that will now be
+    // deactivated.
+    // Withdraws can deactivate a position if they withdraw the exact remaining deposit.
+    //
+    if !position_is_active {
+        account.tokens.deactivate(position_index);
+    }
+
+    Ok(())
+}
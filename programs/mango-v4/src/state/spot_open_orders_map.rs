@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::error::*;
+
+/// Max number of serum markets a single MangoAccount can have resting
+/// OpenOrders accounts for.
+pub const MAX_SERUM_OPEN_ORDERS: usize = 8;
+
+/// Maps a serum_market to the OpenOrders account the MangoAccount uses for it.
+///
+/// This is the only open_orders account that PlaceSerumOrder/CancelSerumOrder/
+/// SettleSerumFunds will accept for that market: it closes the hole where any
+/// open_orders account could previously be passed in.
+#[zero_copy]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+pub struct SpotOpenOrdersMap {
+    pub serum_market: [Pubkey; MAX_SERUM_OPEN_ORDERS],
+    pub open_orders: [Pubkey; MAX_SERUM_OPEN_ORDERS],
+}
+
+impl Default for SpotOpenOrdersMap {
+    fn default() -> Self {
+        Self {
+            serum_market: [Pubkey::default(); MAX_SERUM_OPEN_ORDERS],
+            open_orders: [Pubkey::default(); MAX_SERUM_OPEN_ORDERS],
+        }
+    }
+}
+
+impl SpotOpenOrdersMap {
+    fn index_of(&self, serum_market: Pubkey) -> Option<usize> {
+        self.serum_market
+            .iter()
+            .position(|&market| market == serum_market)
+    }
+
+    pub fn is_registered(&self, serum_market: Pubkey, open_orders: Pubkey) -> bool {
+        match self.index_of(serum_market) {
+            Some(i) => self.open_orders[i] == open_orders,
+            None => false,
+        }
+    }
+
+    pub fn register(&mut self, serum_market: Pubkey, open_orders: Pubkey) -> Result<()> {
+        require!(self.index_of(serum_market).is_none(), MangoError::SomeError);
+        let free_index = self
+            .serum_market
+            .iter()
+            .position(|&market| market == Pubkey::default())
+            .ok_or(error!(MangoError::SomeError))?;
+        self.serum_market[free_index] = serum_market;
+        self.open_orders[free_index] = open_orders;
+        Ok(())
+    }
+
+    pub fn deregister(&mut self, serum_market: Pubkey) -> Result<()> {
+        let index = self
+            .index_of(serum_market)
+            .ok_or(error!(MangoError::SomeError))?;
+        self.serum_market[index] = Pubkey::default();
+        self.open_orders[index] = Pubkey::default();
+        Ok(())
+    }
+}